@@ -0,0 +1,168 @@
+use serde_json::Value;
+
+use crate::config::Profile;
+use crate::error::{Error, Result};
+use crate::session::{self, Session};
+
+/// Thin REST client over a profile's configured `url`/`api_key`. Reuses a
+/// cached session token (see `crate::session`) across invocations instead
+/// of re-authenticating on every command.
+pub struct HttpClient {
+    http: reqwest::Client,
+    base_url: String,
+    token: String,
+}
+
+impl HttpClient {
+    /// Builds a client for `profile`, reusing a still-valid cached session
+    /// for `profile_name` when one exists, and otherwise authenticating
+    /// and caching the resulting session for next time.
+    pub async fn build(profile_name: &str, profile: &Profile) -> Result<Self> {
+        let token = match session::resolve(profile_name, profile)? {
+            Some(session) => session.token,
+            None => {
+                let (token, expires_at) = Self::authenticate(profile).await?;
+                Session::new(profile_name, profile, token.clone(), expires_at).save()?;
+                token
+            }
+        };
+
+        let http = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs_f64(profile.timeout))
+            .build()
+            .map_err(|e| Error::Config(format!("Failed to build HTTP client: {}", e)))?;
+
+        Ok(Self {
+            http,
+            base_url: profile.url.clone(),
+            token,
+        })
+    }
+
+    /// Exchanges the profile's `api_key` for a short-lived session token.
+    async fn authenticate(profile: &Profile) -> Result<(String, f64)> {
+        let api_key = profile
+            .api_key
+            .as_deref()
+            .ok_or_else(|| Error::Config("Profile has no api_key configured".to_string()))?;
+
+        let http = reqwest::Client::new();
+        let response = http
+            .post(format!("{}/v1/auth/session", profile.url))
+            .json(&serde_json::json!({ "api_key": api_key }))
+            .send()
+            .await
+            .map_err(|e| Error::Request(e.to_string()))?;
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|e| Error::Request(e.to_string()))?;
+
+        let token = body
+            .get("token")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| Error::Request("Auth response missing token".to_string()))?
+            .to_string();
+        let expires_at = body
+            .get("expires_at")
+            .and_then(|v| v.as_f64())
+            .ok_or_else(|| Error::Request("Auth response missing expires_at".to_string()))?;
+
+        Ok((token, expires_at))
+    }
+
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<Value>,
+    ) -> Result<Value> {
+        let mut request = self
+            .http
+            .request(method, format!("{}{}", self.base_url, path))
+            .bearer_auth(&self.token);
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| Error::Request(e.to_string()))?;
+        response
+            .json()
+            .await
+            .map_err(|e| Error::Request(e.to_string()))
+    }
+
+    pub async fn admin_create_account(&self, account_id: &str, admin_user_id: &str) -> Result<Value> {
+        self.request(
+            reqwest::Method::POST,
+            "/v1/admin/accounts",
+            Some(serde_json::json!({"account_id": account_id, "admin_user_id": admin_user_id})),
+        )
+        .await
+    }
+
+    pub async fn admin_list_accounts(&self) -> Result<Value> {
+        self.request(reqwest::Method::GET, "/v1/admin/accounts", None).await
+    }
+
+    pub async fn admin_delete_account(&self, account_id: &str) -> Result<Value> {
+        self.request(
+            reqwest::Method::DELETE,
+            &format!("/v1/admin/accounts/{}", account_id),
+            None,
+        )
+        .await
+    }
+
+    pub async fn admin_register_user(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        role: &str,
+    ) -> Result<Value> {
+        self.request(
+            reqwest::Method::POST,
+            &format!("/v1/admin/accounts/{}/users", account_id),
+            Some(serde_json::json!({"user_id": user_id, "role": role})),
+        )
+        .await
+    }
+
+    pub async fn admin_list_users(&self, account_id: &str) -> Result<Value> {
+        self.request(
+            reqwest::Method::GET,
+            &format!("/v1/admin/accounts/{}/users", account_id),
+            None,
+        )
+        .await
+    }
+
+    pub async fn admin_remove_user(&self, account_id: &str, user_id: &str) -> Result<Value> {
+        self.request(
+            reqwest::Method::DELETE,
+            &format!("/v1/admin/accounts/{}/users/{}", account_id, user_id),
+            None,
+        )
+        .await
+    }
+
+    pub async fn admin_set_role(&self, account_id: &str, user_id: &str, role: &str) -> Result<Value> {
+        self.request(
+            reqwest::Method::PUT,
+            &format!("/v1/admin/accounts/{}/users/{}/role", account_id, user_id),
+            Some(serde_json::json!({"role": role})),
+        )
+        .await
+    }
+
+    pub async fn admin_regenerate_key(&self, account_id: &str, user_id: &str) -> Result<Value> {
+        self.request(
+            reqwest::Method::POST,
+            &format!("/v1/admin/accounts/{}/users/{}/regenerate_key", account_id, user_id),
+            None,
+        )
+        .await
+    }
+}
@@ -1,6 +1,7 @@
 use crate::client::HttpClient;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::output::{output_success, OutputFormat};
+use serde::Deserialize;
 use serde_json::json;
 
 pub async fn create_account(
@@ -110,3 +111,157 @@ pub async fn regenerate_key(
     output_success(&response, output_format, compact);
     Ok(())
 }
+
+/// Desired state for a single account and its users, as declared in an
+/// `admin apply` manifest.
+#[derive(Debug, Deserialize)]
+pub struct ApplyManifest {
+    pub account_id: String,
+    pub admin_user_id: String,
+    #[serde(default)]
+    pub users: Vec<ApplyUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApplyUser {
+    pub user_id: String,
+    pub role: String,
+}
+
+/// Parses a manifest file as JSON or YAML. Format is detected from the
+/// file extension, falling back to whether the content looks like a JSON
+/// object, so a syntax error is reported through the parser the user
+/// actually meant to use instead of a misleading YAML error on a file
+/// that was meant to be JSON (or vice versa).
+fn parse_manifest(path: &str, content: &str) -> Result<ApplyManifest> {
+    let looks_like_json = if path.ends_with(".json") {
+        true
+    } else if path.ends_with(".yaml") || path.ends_with(".yml") {
+        false
+    } else {
+        content.trim_start().starts_with('{')
+    };
+    if looks_like_json {
+        serde_json::from_str(content)
+            .map_err(|e| Error::Config(format!("Failed to parse apply manifest {} as JSON: {}", path, e)))
+    } else {
+        serde_yaml::from_str(content)
+            .map_err(|e| Error::Config(format!("Failed to parse apply manifest {} as YAML: {}", path, e)))
+    }
+}
+
+/// Reconciles an account and its users/roles against a declarative
+/// manifest. Computes a diff against the current `admin_list_accounts`/
+/// `admin_list_users` state and issues only the create/register/set_role/
+/// remove calls needed to converge, so the same manifest can be re-run
+/// safely. With `dry_run`, planned actions are reported but nothing is
+/// mutated.
+pub async fn apply(
+    client: &HttpClient,
+    manifest_path: &str,
+    dry_run: bool,
+    output_format: OutputFormat,
+    compact: bool,
+) -> Result<()> {
+    let content = std::fs::read_to_string(manifest_path).map_err(|e| {
+        Error::Config(format!("Failed to read apply manifest {}: {}", manifest_path, e))
+    })?;
+    let manifest = parse_manifest(manifest_path, &content)?;
+
+    let mut created = Vec::new();
+    let mut updated = Vec::new();
+    let mut removed = Vec::new();
+    let mut unchanged = Vec::new();
+
+    let accounts = client.admin_list_accounts().await?;
+    let account_exists = accounts
+        .as_array()
+        .map(|accounts| {
+            accounts.iter().any(|a| {
+                a.get("account_id").and_then(|v| v.as_str()) == Some(manifest.account_id.as_str())
+            })
+        })
+        .unwrap_or(false);
+
+    if !account_exists {
+        created.push(json!({"kind": "account", "account_id": manifest.account_id}));
+        if !dry_run {
+            client
+                .admin_create_account(&manifest.account_id, &manifest.admin_user_id)
+                .await?;
+        }
+    } else {
+        unchanged.push(json!({"kind": "account", "account_id": manifest.account_id}));
+    }
+
+    let existing_users = if account_exists {
+        client.admin_list_users(&manifest.account_id).await?
+    } else {
+        json!([])
+    };
+    let existing_users: Vec<(String, String)> = existing_users
+        .as_array()
+        .map(|users| {
+            users
+                .iter()
+                .filter_map(|u| {
+                    let user_id = u.get("user_id").and_then(|v| v.as_str())?.to_string();
+                    let role = u.get("role").and_then(|v| v.as_str())?.to_string();
+                    Some((user_id, role))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for desired in &manifest.users {
+        match existing_users.iter().find(|(user_id, _)| user_id == &desired.user_id) {
+            None => {
+                created.push(json!({"kind": "user", "user_id": desired.user_id, "role": desired.role}));
+                if !dry_run {
+                    client
+                        .admin_register_user(&manifest.account_id, &desired.user_id, &desired.role)
+                        .await?;
+                }
+            }
+            Some((_, existing_role)) if existing_role != &desired.role => {
+                updated.push(json!({"kind": "user", "user_id": desired.user_id, "role": desired.role}));
+                if !dry_run {
+                    client
+                        .admin_set_role(&manifest.account_id, &desired.user_id, &desired.role)
+                        .await?;
+                }
+            }
+            Some(_) => {
+                unchanged.push(json!({"kind": "user", "user_id": desired.user_id}));
+            }
+        }
+    }
+
+    for (user_id, _) in &existing_users {
+        if user_id == &manifest.admin_user_id {
+            // The admin user is implicitly created by admin_create_account
+            // and isn't declared in `users`; removing it would make
+            // re-applying the same manifest destructive.
+            if !manifest.users.iter().any(|u| &u.user_id == user_id) {
+                unchanged.push(json!({"kind": "user", "user_id": user_id}));
+            }
+            continue;
+        }
+        if !manifest.users.iter().any(|u| &u.user_id == user_id) {
+            removed.push(json!({"kind": "user", "user_id": user_id}));
+            if !dry_run {
+                client.admin_remove_user(&manifest.account_id, user_id).await?;
+            }
+        }
+    }
+
+    let summary = json!({
+        "dry_run": dry_run,
+        "created": created,
+        "updated": updated,
+        "removed": removed,
+        "unchanged": unchanged,
+    });
+    output_success(&summary, output_format, compact);
+    Ok(())
+}
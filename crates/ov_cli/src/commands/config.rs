@@ -0,0 +1,101 @@
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::output::{output_success, OutputFormat};
+use serde_json::json;
+
+const VALID_KEYS: &[&str] = &["url", "api_key", "agent_id", "timeout", "output", "echo_command"];
+
+fn redacted_value(key: &str, config: &Config) -> Result<serde_json::Value> {
+    let profile = config.active()?;
+    Ok(match key {
+        "url" => json!(profile.url),
+        "api_key" => json!(profile.api_key.as_ref().map(|_| "********")),
+        "agent_id" => json!(profile.agent_id),
+        "timeout" => json!(profile.timeout),
+        "output" => json!(profile.output),
+        "echo_command" => json!(profile.echo_command),
+        _ => {
+            return Err(Error::Config(format!(
+                "Unknown config key {:?}, expected one of {:?}",
+                key, VALID_KEYS
+            )))
+        }
+    })
+}
+
+/// Prints the effective, env-overridden config for the active profile,
+/// with `api_key` redacted.
+pub fn show(config: &Config, output_format: OutputFormat, compact: bool) -> Result<()> {
+    let mut fields = serde_json::Map::new();
+    fields.insert(
+        "current_profile".to_string(),
+        json!(config.current_profile),
+    );
+    for key in VALID_KEYS {
+        fields.insert(key.to_string(), redacted_value(key, config)?);
+    }
+    output_success(&json!(fields), output_format, compact);
+    Ok(())
+}
+
+/// Prints a single config key from the active profile, with `api_key`
+/// redacted.
+pub fn get(config: &Config, key: &str, output_format: OutputFormat, compact: bool) -> Result<()> {
+    let value = redacted_value(key, config)?;
+    output_success(&json!({ key: value }), output_format, compact);
+    Ok(())
+}
+
+/// Validates `key` against the known `Profile` fields, coerces `value`
+/// to the right type, updates the active profile, and persists the
+/// change via `Config::save_default`.
+///
+/// Mutates a freshly loaded on-disk config (`Config::load_for_edit`)
+/// rather than `config`, since `config` may already have `OPENVIKING_*`
+/// env overrides baked into its active profile by `load_default` — saving
+/// those back to `ovcli.conf` would leak an env-supplied value (e.g.
+/// `OPENVIKING_API_KEY`) to disk.
+pub fn set(
+    config: &Config,
+    key: &str,
+    value: &str,
+    output_format: OutputFormat,
+    compact: bool,
+) -> Result<()> {
+    if !VALID_KEYS.contains(&key) {
+        return Err(Error::Config(format!(
+            "Unknown config key {:?}, expected one of {:?}",
+            key, VALID_KEYS
+        )));
+    }
+
+    let mut on_disk = Config::load_for_edit()?;
+    on_disk.switch(&config.current_profile);
+    let profile = on_disk.active_mut();
+    match key {
+        "url" => profile.url = value.to_string(),
+        "api_key" => profile.api_key = Some(value.to_string()),
+        "agent_id" => profile.agent_id = Some(value.to_string()),
+        "timeout" => {
+            profile.timeout = value.parse().map_err(|_| {
+                Error::Config(format!("Invalid value for timeout: {:?} is not a number", value))
+            })?;
+        }
+        "output" => profile.output = value.to_string(),
+        "echo_command" => {
+            profile.echo_command = value.parse().map_err(|_| {
+                Error::Config(format!(
+                    "Invalid value for echo_command: {:?} is not a boolean",
+                    value
+                ))
+            })?;
+        }
+        _ => unreachable!("key was validated against VALID_KEYS above"),
+    }
+
+    on_disk.save_default()?;
+
+    let display_value = if key == "api_key" { json!("********") } else { json!(value) };
+    output_success(&json!({ key: display_value }), output_format, compact);
+    Ok(())
+}
@@ -1,12 +1,22 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::error::{Error, Result};
 
 const OPENVIKING_CLI_CONFIG_ENV: &str = "OPENVIKING_CLI_CONFIG_FILE";
+const OPENVIKING_CLI_PROFILE_ENV: &str = "OPENVIKING_CLI_PROFILE";
+const DEFAULT_PROFILE: &str = "default";
+
+const OPENVIKING_URL_ENV: &str = "OPENVIKING_URL";
+const OPENVIKING_API_KEY_ENV: &str = "OPENVIKING_API_KEY";
+const OPENVIKING_AGENT_ID_ENV: &str = "OPENVIKING_AGENT_ID";
+const OPENVIKING_TIMEOUT_ENV: &str = "OPENVIKING_TIMEOUT";
+const OPENVIKING_OUTPUT_ENV: &str = "OPENVIKING_OUTPUT";
+const OPENVIKING_ECHO_COMMAND_ENV: &str = "OPENVIKING_ECHO_COMMAND";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Config {
+pub struct Profile {
     #[serde(default = "default_url")]
     pub url: String,
     pub api_key: Option<String>,
@@ -19,6 +29,19 @@ pub struct Config {
     pub echo_command: bool,
 }
 
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            url: default_url(),
+            api_key: None,
+            agent_id: None,
+            timeout: default_timeout(),
+            output: default_output_format(),
+            echo_command: default_echo_command(),
+        }
+    }
+}
+
 fn default_url() -> String {
     "http://localhost:1933".to_string()
 }
@@ -35,15 +58,59 @@ fn default_echo_command() -> bool {
     true
 }
 
+fn default_current_profile() -> String {
+    DEFAULT_PROFILE.to_string()
+}
+
+fn default_profiles() -> HashMap<String, Profile> {
+    let mut profiles = HashMap::new();
+    profiles.insert(DEFAULT_PROFILE.to_string(), Profile::default());
+    profiles
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default = "default_current_profile")]
+    pub current_profile: String,
+    #[serde(default = "default_profiles")]
+    pub profiles: HashMap<String, Profile>,
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
-            url: "http://localhost:1933".to_string(),
-            api_key: None,
-            agent_id: None,
-            timeout: 60.0,
-            output: "table".to_string(),
-            echo_command: true,
+            current_profile: default_current_profile(),
+            profiles: default_profiles(),
+        }
+    }
+}
+
+/// A legacy flat config file, predating named profiles. `from_file` falls
+/// back to this shape and adopts it as the implicit `default` profile.
+#[derive(Debug, Clone, Deserialize)]
+struct LegacyConfig {
+    #[serde(default = "default_url")]
+    url: String,
+    api_key: Option<String>,
+    agent_id: Option<String>,
+    #[serde(default = "default_timeout")]
+    timeout: f64,
+    #[serde(default = "default_output_format")]
+    output: String,
+    #[serde(default = "default_echo_command")]
+    echo_command: bool,
+}
+
+impl From<LegacyConfig> for Profile {
+    fn from(legacy: LegacyConfig) -> Self {
+        Self {
+            url: legacy.url,
+            api_key: legacy.api_key,
+            agent_id: legacy.agent_id,
+            timeout: legacy.timeout,
+            output: legacy.output,
+            echo_command: legacy.echo_command,
         }
     }
 }
@@ -55,42 +122,165 @@ impl Config {
     }
 
     pub fn load_default() -> Result<Self> {
+        let mut config = Self::load_from_disk()?;
+        apply_env_overrides(config.active_mut())?;
+        Ok(config)
+    }
+
+    /// Loads the on-disk config plus the `OPENVIKING_CLI_PROFILE` profile
+    /// selection, but without applying `OPENVIKING_*` value overrides.
+    /// Used by callers that persist edits (e.g. `config set`) so an
+    /// env-supplied value never gets written back into `ovcli.conf`.
+    pub fn load_for_edit() -> Result<Self> {
+        Self::load_from_disk()
+    }
+
+    fn load_from_disk() -> Result<Self> {
         // Resolution order: env var > default path
-        if let Ok(env_path) = std::env::var(OPENVIKING_CLI_CONFIG_ENV) {
+        let mut config = if let Ok(env_path) = std::env::var(OPENVIKING_CLI_CONFIG_ENV) {
             let p = PathBuf::from(env_path);
             if p.exists() {
-                return Self::from_file(&p.to_string_lossy());
+                Self::from_file(&p)?
+            } else {
+                Self::default()
             }
-        }
-
-        let config_path = default_config_path()?;
-        if config_path.exists() {
-            Self::from_file(&config_path.to_string_lossy())
         } else {
-            Ok(Self::default())
+            let config_path = default_config_path()?;
+            if config_path.exists() {
+                Self::from_file(&config_path)?
+            } else {
+                Self::default()
+            }
+        };
+
+        if let Ok(profile) = std::env::var(OPENVIKING_CLI_PROFILE_ENV) {
+            if !config.profiles.contains_key(&profile) {
+                config.profiles.insert(profile.clone(), Profile::default());
+            }
+            config.current_profile = profile;
         }
-    }
 
-    pub fn from_file(path: &str) -> Result<Self> {
-        let content = std::fs::read_to_string(path)
-            .map_err(|e| Error::Config(format!("Failed to read config file: {}", e)))?;
-        let config: Config = serde_json::from_str(&content)
-            .map_err(|e| Error::Config(format!("Failed to parse config file: {}", e)))?;
         Ok(config)
     }
 
+    pub fn from_file(path: &std::path::Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            Error::Config(format!("Failed to read config file {}: {}", path.display(), e))
+        })?;
+
+        if let Ok(config) = serde_json::from_str::<Config>(&content) {
+            return Ok(config);
+        }
+
+        let legacy: LegacyConfig = serde_json::from_str(&content).map_err(|e| {
+            Error::Config(format!("Failed to parse config file {}: {}", path.display(), e))
+        })?;
+        let mut profiles = HashMap::new();
+        profiles.insert(DEFAULT_PROFILE.to_string(), Profile::from(legacy));
+        Ok(Config {
+            current_profile: default_current_profile(),
+            profiles,
+        })
+    }
+
+    /// Writes the config to its default location atomically: the new
+    /// contents are written to a sibling `.tmp` file and then renamed into
+    /// place, so a crash or full disk mid-write never leaves readers with a
+    /// truncated, unparseable config.
     pub fn save_default(&self) -> Result<()> {
         let config_path = default_config_path()?;
         if let Some(parent) = config_path.parent() {
-            std::fs::create_dir_all(parent)
-                .map_err(|e| Error::Config(format!("Failed to create config directory: {}", e)))?;
+            std::fs::create_dir_all(parent).map_err(|e| {
+                Error::Config(format!(
+                    "Failed to create config directory {}: {}",
+                    parent.display(),
+                    e
+                ))
+            })?;
         }
         let content = serde_json::to_string_pretty(self)
             .map_err(|e| Error::Config(format!("Failed to serialize config: {}", e)))?;
-        std::fs::write(&config_path, content)
-            .map_err(|e| Error::Config(format!("Failed to write config file: {}", e)))?;
+
+        let tmp_path = config_path.with_extension("conf.tmp");
+        std::fs::write(&tmp_path, content).map_err(|e| {
+            Error::Config(format!(
+                "Failed to write config file {}: {}",
+                tmp_path.display(),
+                e
+            ))
+        })?;
+        std::fs::rename(&tmp_path, &config_path).map_err(|e| {
+            Error::Config(format!(
+                "Failed to write config file {}: {}",
+                config_path.display(),
+                e
+            ))
+        })?;
         Ok(())
     }
+
+    /// Returns the profile that commands should read their settings from.
+    pub fn active(&self) -> Result<&Profile> {
+        self.profiles.get(&self.current_profile).ok_or_else(|| {
+            Error::Config(format!(
+                "current profile {:?} has no matching entry in profiles",
+                self.current_profile
+            ))
+        })
+    }
+
+    /// Returns the profile that commands should read their settings from, mutably.
+    pub fn active_mut(&mut self) -> &mut Profile {
+        let name = self.current_profile.clone();
+        self.profiles
+            .entry(name)
+            .or_insert_with(Profile::default)
+    }
+
+    /// Switches the active profile, creating it with defaults if it doesn't exist yet.
+    pub fn switch(&mut self, name: &str) {
+        self.profiles
+            .entry(name.to_string())
+            .or_insert_with(Profile::default);
+        self.current_profile = name.to_string();
+    }
+}
+
+/// Applies single-field env var overrides on top of a loaded profile.
+/// Precedence is env var > file value > `default_*()`; unset variables
+/// leave the existing value untouched. Parse failures are surfaced as
+/// `Error::Config` naming the offending variable so misconfigured
+/// deployments fail loudly instead of silently using defaults.
+fn apply_env_overrides(profile: &mut Profile) -> Result<()> {
+    if let Ok(v) = std::env::var(OPENVIKING_URL_ENV) {
+        profile.url = v;
+    }
+    if let Ok(v) = std::env::var(OPENVIKING_API_KEY_ENV) {
+        profile.api_key = Some(v);
+    }
+    if let Ok(v) = std::env::var(OPENVIKING_AGENT_ID_ENV) {
+        profile.agent_id = Some(v);
+    }
+    if let Ok(v) = std::env::var(OPENVIKING_TIMEOUT_ENV) {
+        profile.timeout = v.parse().map_err(|_| {
+            Error::Config(format!(
+                "Invalid value for {}: {:?} is not a number",
+                OPENVIKING_TIMEOUT_ENV, v
+            ))
+        })?;
+    }
+    if let Ok(v) = std::env::var(OPENVIKING_OUTPUT_ENV) {
+        profile.output = v;
+    }
+    if let Ok(v) = std::env::var(OPENVIKING_ECHO_COMMAND_ENV) {
+        profile.echo_command = v.parse().map_err(|_| {
+            Error::Config(format!(
+                "Invalid value for {}: {:?} is not a boolean",
+                OPENVIKING_ECHO_COMMAND_ENV, v
+            ))
+        })?;
+    }
+    Ok(())
 }
 
 pub fn default_config_path() -> Result<PathBuf> {
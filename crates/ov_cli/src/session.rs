@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::Profile;
+use crate::error::{Error, Result};
+
+/// A cached, negotiated session for one profile, stashed next to
+/// `ovcli.conf` in a file keyed by profile name so alternating between
+/// profiles doesn't evict each other's cached session. Profile names that
+/// differ only in characters replaced by `sanitize_profile_name` share a file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub token: String,
+    pub expires_at: f64,
+    pub profile: String,
+    pub agent_id: Option<String>,
+    url_fingerprint: u64,
+    api_key_fingerprint: u64,
+}
+
+impl Session {
+    pub fn new(profile_name: &str, profile: &Profile, token: String, expires_at: f64) -> Self {
+        Self {
+            token,
+            expires_at,
+            profile: profile_name.to_string(),
+            agent_id: profile.agent_id.clone(),
+            url_fingerprint: fingerprint(&profile.url),
+            api_key_fingerprint: fingerprint(profile.api_key.as_deref().unwrap_or("")),
+        }
+    }
+
+    /// Loads the cached session for `profile_name`, if one exists on disk.
+    pub fn load(profile_name: &str) -> Result<Option<Self>> {
+        let path = session_path(profile_name)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path).map_err(|e| {
+            Error::Config(format!("Failed to read session cache {}: {}", path.display(), e))
+        })?;
+        let session: Session = serde_json::from_str(&content).map_err(|e| {
+            Error::Config(format!("Failed to parse session cache {}: {}", path.display(), e))
+        })?;
+        Ok(Some(session))
+    }
+
+    /// Writes the session cache atomically: a sibling `.tmp` file is
+    /// written and renamed into place, matching how `Config::save_default`
+    /// persists `ovcli.conf`.
+    pub fn save(&self) -> Result<()> {
+        let path = session_path(&self.profile)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                Error::Config(format!(
+                    "Failed to create config directory {}: {}",
+                    parent.display(),
+                    e
+                ))
+            })?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| Error::Config(format!("Failed to serialize session cache: {}", e)))?;
+
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, content).map_err(|e| {
+            Error::Config(format!(
+                "Failed to write session cache {}: {}",
+                tmp_path.display(),
+                e
+            ))
+        })?;
+        std::fs::rename(&tmp_path, &path).map_err(|e| {
+            Error::Config(format!(
+                "Failed to write session cache {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Ok(())
+    }
+
+    /// Removes the cached session for `profile_name`, e.g. when that
+    /// profile's `api_key`/`url` no longer matches what the cache was
+    /// issued for.
+    pub fn clear(profile_name: &str) -> Result<()> {
+        let path = session_path(profile_name)?;
+        if path.exists() {
+            std::fs::remove_file(&path).map_err(|e| {
+                Error::Config(format!(
+                    "Failed to remove session cache {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Whether this cached session is still usable for `profile`: it
+    /// belongs to the same profile, was minted for the same `url`/`api_key`,
+    /// and hasn't expired.
+    pub fn is_valid_for(&self, profile_name: &str, profile: &Profile) -> bool {
+        self.profile == profile_name
+            && self.url_fingerprint == fingerprint(&profile.url)
+            && self.api_key_fingerprint == fingerprint(profile.api_key.as_deref().unwrap_or(""))
+            && self.expires_at > now()
+    }
+}
+
+/// Returns a cached, still-valid session for `profile`, if any. Callers
+/// constructing an `HttpClient` should use this to skip re-auth, and
+/// overwrite the cache (via `Session::save`) once they refresh an expired
+/// or missing session. Each profile has its own cache file, so resolving
+/// one profile does not evict another's cached session, except for
+/// profiles whose names map to the same file after `sanitize_profile_name`.
+pub fn resolve(profile_name: &str, profile: &Profile) -> Result<Option<Session>> {
+    match Session::load(profile_name)? {
+        Some(session) if session.is_valid_for(profile_name, profile) => Ok(Some(session)),
+        Some(_) => {
+            Session::clear(profile_name)?;
+            Ok(None)
+        }
+        None => Ok(None),
+    }
+}
+
+fn fingerprint(value: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn now() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or(0.0)
+}
+
+/// Session cache file names are keyed by profile so that e.g. `dev` and
+/// `prod` each keep their own cached token instead of overwriting a
+/// single shared file.
+pub fn session_path(profile_name: &str) -> Result<PathBuf> {
+    let home = dirs::home_dir()
+        .ok_or_else(|| Error::Config("Could not determine home directory".to_string()))?;
+    Ok(home
+        .join(".openviking")
+        .join(format!("session-{}.json", sanitize_profile_name(profile_name))))
+}
+
+/// Profile names are user-supplied and may contain path separators or
+/// other characters unsafe in a filename; collapse anything that isn't
+/// alphanumeric, `-`, or `_` to `_`.
+fn sanitize_profile_name(profile_name: &str) -> String {
+    profile_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}